@@ -15,9 +15,62 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use handlebars::{
-    Context, Handlebars, Helper, HelperDef, Output, RenderContext, RenderError, RenderErrorReason,
-    Renderable,
+    Context, Handlebars, Helper, HelperDef, JsonTruthy, Output, RenderContext, RenderError,
+    RenderErrorReason, Renderable, ScopedJson,
 };
+use log::Level;
+use serde_json::Value;
+use std::cmp::Ordering;
+use std::str::FromStr;
+
+/// Compares two values for equality, optionally coercing between types.
+///
+/// With `coerce` off (the default), this is byte-exact `serde_json::Value`
+/// equality, so `1` and `1.0` or `0` and `false` are not equal.
+///
+/// With `coerce` on, numbers are promoted to `f64` before comparison (so `1`
+/// equals `1.0`), strings and numbers are compared via their string
+/// representation (so `"1"` equals `1`), and if either side is a boolean the
+/// two values are compared by Handlebars truthiness instead, matching
+/// upstream `{{#if}}` semantics. `include_zero` is forwarded to that
+/// truthiness check so `0` only counts as truthy (and thus equal to `true`)
+/// when explicitly requested.
+fn values_equal(first: &Value, second: &Value, coerce: bool, include_zero: bool) -> bool {
+    if first == second {
+        return true;
+    }
+    if !coerce {
+        return false;
+    }
+
+    if let (Some(a), Some(b)) = (first.as_f64(), second.as_f64()) {
+        return a == b;
+    }
+
+    if first.is_boolean() || second.is_boolean() {
+        return first.is_truthy(include_zero) == second.is_truthy(include_zero);
+    }
+
+    fn scalar_string(value: &Value) -> Option<String> {
+        match value {
+            Value::String(s) => Some(s.clone()),
+            Value::Number(n) => Some(n.to_string()),
+            _ => None,
+        }
+    }
+
+    match (scalar_string(first), scalar_string(second)) {
+        (Some(a), Some(b)) => a == b,
+        _ => false,
+    }
+}
+
+/// Reads a boolean hash argument, defaulting to `false` when absent.
+fn hash_flag(h: &Helper<'_>, key: &'static str) -> bool {
+    h.hash_get(key)
+        .map(|v| v.value().as_bool().unwrap_or(false))
+        .unwrap_or(false)
+}
 
 /// Helper for comparing equality between two values.
 ///
@@ -39,6 +92,15 @@ use handlebars::{
 /// * `arg1`: The first argument to compare.
 /// * `arg2`: The second argument to compare.
 ///
+/// ## Hash Arguments
+///
+/// * `coerce`: Optional. When `true`, compares with numeric promotion (`1`
+///   equals `1.0`) and string/number/boolean coercion instead of byte-exact
+///   `serde_json::Value` equality. Defaults to `false`.
+/// * `includeZero`: Optional. When `coerce` is `true` and either side is a
+///   boolean, controls whether `0` counts as truthy, matching the
+///   `includeZero` flag on upstream `{{#if}}`. Defaults to `false`.
+///
 /// The helper renders the template block if `arg1` is equal to `arg2`.
 /// Otherwise, it renders the inverse block (if provided).
 #[derive(Clone, Copy, Debug)]
@@ -59,8 +121,10 @@ impl HelperDef for IfEqualsHelper {
         let second = h.param(1).ok_or_else(|| {
             RenderError::from(RenderErrorReason::ParamNotFoundForIndex("ifEquals", 1))
         })?;
+        let coerce = hash_flag(h, "coerce");
+        let include_zero = hash_flag(h, "includeZero");
 
-        if first.value() == second.value() {
+        if values_equal(first.value(), second.value(), coerce, include_zero) {
             if let Some(template) = h.template() {
                 template.render(reg, ctx, rc, out)?;
             }
@@ -128,6 +192,76 @@ mod if_equals_tests {
             ""
         );
     }
+
+    #[test]
+    fn without_coerce_int_and_float_are_not_equal() {
+        let mut handlebars = Handlebars::new();
+        handlebars.register_helper("ifEquals", Box::new(IfEqualsHelper {}));
+
+        assert_eq!(
+            handlebars
+                .render_template("{{#ifEquals 1 1.0}}yes{{else}}no{{/ifEquals}}", &json!({}))
+                .unwrap(),
+            "no"
+        );
+    }
+
+    #[test]
+    fn with_coerce_int_and_float_are_equal() {
+        let mut handlebars = Handlebars::new();
+        handlebars.register_helper("ifEquals", Box::new(IfEqualsHelper {}));
+
+        assert_eq!(
+            handlebars
+                .render_template(
+                    "{{#ifEquals 1 1.0 coerce=true}}yes{{else}}no{{/ifEquals}}",
+                    &json!({})
+                )
+                .unwrap(),
+            "yes"
+        );
+    }
+
+    #[test]
+    fn with_coerce_string_and_number_are_equal() {
+        let mut handlebars = Handlebars::new();
+        handlebars.register_helper("ifEquals", Box::new(IfEqualsHelper {}));
+
+        assert_eq!(
+            handlebars
+                .render_template(
+                    "{{#ifEquals \"1\" 1 coerce=true}}yes{{else}}no{{/ifEquals}}",
+                    &json!({})
+                )
+                .unwrap(),
+            "yes"
+        );
+    }
+
+    #[test]
+    fn with_coerce_zero_is_not_truthy_unless_include_zero() {
+        let mut handlebars = Handlebars::new();
+        handlebars.register_helper("ifEquals", Box::new(IfEqualsHelper {}));
+
+        assert_eq!(
+            handlebars
+                .render_template(
+                    "{{#ifEquals 0 true coerce=true}}yes{{else}}no{{/ifEquals}}",
+                    &json!({})
+                )
+                .unwrap(),
+            "no"
+        );
+        assert_eq!(
+            handlebars
+                .render_template(
+                    "{{#ifEquals 0 true coerce=true includeZero=true}}yes{{else}}no{{/ifEquals}}",
+                    &json!({})
+                )
+                .unwrap(),
+            "yes"
+        );
+    }
 }
 
 /// Helper for comparing inequality between two values.
@@ -150,6 +284,13 @@ mod if_equals_tests {
 /// * `arg1`: The first argument to compare.
 /// * `arg2`: The second argument to compare.
 ///
+/// ## Hash Arguments
+///
+/// * `coerce`: Optional. Same meaning as on [`IfEqualsHelper`]. Defaults to
+///   `false`.
+/// * `includeZero`: Optional. Same meaning as on [`IfEqualsHelper`]. Defaults
+///   to `false`.
+///
 /// The helper renders the template block if `arg1` is not equal to `arg2`.
 /// Otherwise, it renders the inverse block (if provided).
 #[derive(Clone, Copy, Debug)]
@@ -170,8 +311,10 @@ impl HelperDef for UnlessEqualsHelper {
         let second = h.param(1).ok_or_else(|| {
             RenderError::from(RenderErrorReason::ParamNotFoundForIndex("unlessEquals", 1))
         })?;
+        let coerce = hash_flag(h, "coerce");
+        let include_zero = hash_flag(h, "includeZero");
 
-        if first.value() != second.value() {
+        if !values_equal(first.value(), second.value(), coerce, include_zero) {
             if let Some(template) = h.template() {
                 template.render(reg, ctx, rc, out)?;
             }
@@ -245,6 +388,417 @@ mod unless_equals_tests {
             ""
         );
     }
+
+    #[test]
+    fn with_coerce_int_and_float_are_equal() {
+        let mut handlebars = Handlebars::new();
+        handlebars.register_helper("unlessEquals", Box::new(UnlessEqualsHelper {}));
+
+        assert_eq!(
+            handlebars
+                .render_template(
+                    "{{#unlessEquals 1 1.0 coerce=true}}yes{{else}}no{{/unlessEquals}}",
+                    &json!({})
+                )
+                .unwrap(),
+            "no"
+        );
+    }
+}
+
+/// Compares two `serde_json::Value`s for ordering.
+///
+/// Numbers (integers and floats alike) are promoted to `f64` and compared
+/// numerically. Strings are compared lexically. Any other combination of
+/// types (or a mix of number/string) is not comparable and raises a
+/// `RenderError`.
+fn compare_values(
+    helper_name: &'static str,
+    first: &Value,
+    second: &Value,
+) -> Result<Ordering, RenderError> {
+    match (first.as_f64(), second.as_f64()) {
+        (Some(a), Some(b)) => a.partial_cmp(&b).ok_or_else(|| {
+            RenderError::from(RenderErrorReason::Other(format!(
+                "{}: cannot compare NaN values",
+                helper_name
+            )))
+        }),
+        _ => match (first.as_str(), second.as_str()) {
+            (Some(a), Some(b)) => Ok(a.cmp(b)),
+            _ => Err(RenderError::from(RenderErrorReason::Other(format!(
+                "{}: arguments must both be numbers or both be strings, got {} and {}",
+                helper_name, first, second
+            )))),
+        },
+    }
+}
+
+/// Helper for numeric/lexical "greater than" comparison.
+///
+/// Returns `true` if `arg1` is greater than `arg2`, comparing numerically
+/// when both arguments are numbers (integers are promoted to `f64`) or
+/// lexically when both are strings. Raises a `RenderError` if the
+/// arguments are of mismatched or uncomparable types.
+///
+/// The boolean result is for use as an inline subexpression, e.g.
+/// `{{#if (gt a 3)}}`. Called as a block helper (`{{#gt a b}}...{{/gt}}`),
+/// it writes `true` or `false` and renders neither the block nor its
+/// inverse.
+///
+/// ## Usage
+///
+/// ```handlebars
+/// {{#if (gt arg1 arg2)}}
+///   <p>arg1 is greater than arg2</p>
+/// {{else}}
+///   <p>arg1 is not greater than arg2</p>
+/// {{/if}}
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct GtHelper {}
+
+impl HelperDef for GtHelper {
+    fn call_inner<'reg: 'rc, 'rc>(
+        &self,
+        h: &Helper<'rc>,
+        _reg: &'reg Handlebars<'reg>,
+        _ctx: &'rc Context,
+        _rc: &mut RenderContext<'reg, 'rc>,
+    ) -> Result<ScopedJson<'reg, 'rc>, RenderError> {
+        let first = h.param(0).ok_or_else(|| {
+            RenderError::from(RenderErrorReason::ParamNotFoundForIndex("gt", 0))
+        })?;
+        let second = h.param(1).ok_or_else(|| {
+            RenderError::from(RenderErrorReason::ParamNotFoundForIndex("gt", 1))
+        })?;
+
+        let result = compare_values("gt", first.value(), second.value())? == Ordering::Greater;
+        Ok(ScopedJson::Derived(Value::Bool(result)))
+    }
+}
+
+/// Helper for numeric/lexical "greater than or equal to" comparison.
+///
+/// See [`GtHelper`] for comparison semantics.
+#[derive(Clone, Copy, Debug)]
+pub struct GteHelper {}
+
+impl HelperDef for GteHelper {
+    fn call_inner<'reg: 'rc, 'rc>(
+        &self,
+        h: &Helper<'rc>,
+        _reg: &'reg Handlebars<'reg>,
+        _ctx: &'rc Context,
+        _rc: &mut RenderContext<'reg, 'rc>,
+    ) -> Result<ScopedJson<'reg, 'rc>, RenderError> {
+        let first = h.param(0).ok_or_else(|| {
+            RenderError::from(RenderErrorReason::ParamNotFoundForIndex("gte", 0))
+        })?;
+        let second = h.param(1).ok_or_else(|| {
+            RenderError::from(RenderErrorReason::ParamNotFoundForIndex("gte", 1))
+        })?;
+
+        let result = compare_values("gte", first.value(), second.value())? != Ordering::Less;
+        Ok(ScopedJson::Derived(Value::Bool(result)))
+    }
+}
+
+/// Helper for numeric/lexical "less than" comparison.
+///
+/// See [`GtHelper`] for comparison semantics.
+#[derive(Clone, Copy, Debug)]
+pub struct LtHelper {}
+
+impl HelperDef for LtHelper {
+    fn call_inner<'reg: 'rc, 'rc>(
+        &self,
+        h: &Helper<'rc>,
+        _reg: &'reg Handlebars<'reg>,
+        _ctx: &'rc Context,
+        _rc: &mut RenderContext<'reg, 'rc>,
+    ) -> Result<ScopedJson<'reg, 'rc>, RenderError> {
+        let first = h.param(0).ok_or_else(|| {
+            RenderError::from(RenderErrorReason::ParamNotFoundForIndex("lt", 0))
+        })?;
+        let second = h.param(1).ok_or_else(|| {
+            RenderError::from(RenderErrorReason::ParamNotFoundForIndex("lt", 1))
+        })?;
+
+        let result = compare_values("lt", first.value(), second.value())? == Ordering::Less;
+        Ok(ScopedJson::Derived(Value::Bool(result)))
+    }
+}
+
+/// Helper for numeric/lexical "less than or equal to" comparison.
+///
+/// See [`GtHelper`] for comparison semantics.
+#[derive(Clone, Copy, Debug)]
+pub struct LteHelper {}
+
+impl HelperDef for LteHelper {
+    fn call_inner<'reg: 'rc, 'rc>(
+        &self,
+        h: &Helper<'rc>,
+        _reg: &'reg Handlebars<'reg>,
+        _ctx: &'rc Context,
+        _rc: &mut RenderContext<'reg, 'rc>,
+    ) -> Result<ScopedJson<'reg, 'rc>, RenderError> {
+        let first = h.param(0).ok_or_else(|| {
+            RenderError::from(RenderErrorReason::ParamNotFoundForIndex("lte", 0))
+        })?;
+        let second = h.param(1).ok_or_else(|| {
+            RenderError::from(RenderErrorReason::ParamNotFoundForIndex("lte", 1))
+        })?;
+
+        let result = compare_values("lte", first.value(), second.value())? != Ordering::Greater;
+        Ok(ScopedJson::Derived(Value::Bool(result)))
+    }
+}
+
+/// Helper for inequality comparison, the complement of [`IfEqualsHelper`],
+/// returning a boolean for use as an inline subexpression. Called as a block
+/// helper (`{{#ne a b}}...{{/ne}}`), it writes `true` or `false` and renders
+/// neither the block nor its inverse.
+///
+/// ## Usage
+///
+/// ```handlebars
+/// {{#if (ne arg1 arg2)}}
+///   <p>arg1 is not equal to arg2</p>
+/// {{/if}}
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct NeHelper {}
+
+impl HelperDef for NeHelper {
+    fn call_inner<'reg: 'rc, 'rc>(
+        &self,
+        h: &Helper<'rc>,
+        _reg: &'reg Handlebars<'reg>,
+        _ctx: &'rc Context,
+        _rc: &mut RenderContext<'reg, 'rc>,
+    ) -> Result<ScopedJson<'reg, 'rc>, RenderError> {
+        let first = h.param(0).ok_or_else(|| {
+            RenderError::from(RenderErrorReason::ParamNotFoundForIndex("ne", 0))
+        })?;
+        let second = h.param(1).ok_or_else(|| {
+            RenderError::from(RenderErrorReason::ParamNotFoundForIndex("ne", 1))
+        })?;
+
+        Ok(ScopedJson::Derived(Value::Bool(
+            first.value() != second.value(),
+        )))
+    }
+}
+
+/// Helper for variadic logical AND, truthy-evaluating every parameter using
+/// Handlebars' own truthiness rules (see [`JsonTruthy`]).
+///
+/// ## Usage
+///
+/// ```handlebars
+/// {{#if (and (gt a 3) (lt a 9))}}
+///   <p>a is between 3 and 9</p>
+/// {{/if}}
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct AndHelper {}
+
+impl HelperDef for AndHelper {
+    fn call_inner<'reg: 'rc, 'rc>(
+        &self,
+        h: &Helper<'rc>,
+        _reg: &'reg Handlebars<'reg>,
+        _ctx: &'rc Context,
+        _rc: &mut RenderContext<'reg, 'rc>,
+    ) -> Result<ScopedJson<'reg, 'rc>, RenderError> {
+        let result = h
+            .params()
+            .iter()
+            .all(|p| p.value().is_truthy(false));
+        Ok(ScopedJson::Derived(Value::Bool(result)))
+    }
+}
+
+/// Helper for variadic logical OR, truthy-evaluating every parameter using
+/// Handlebars' own truthiness rules (see [`JsonTruthy`]).
+///
+/// ## Usage
+///
+/// ```handlebars
+/// {{#if (or (gt a 3) (lt a 9))}}
+///   <p>a is outside of [3, 9] or inside it</p>
+/// {{/if}}
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct OrHelper {}
+
+impl HelperDef for OrHelper {
+    fn call_inner<'reg: 'rc, 'rc>(
+        &self,
+        h: &Helper<'rc>,
+        _reg: &'reg Handlebars<'reg>,
+        _ctx: &'rc Context,
+        _rc: &mut RenderContext<'reg, 'rc>,
+    ) -> Result<ScopedJson<'reg, 'rc>, RenderError> {
+        let result = h
+            .params()
+            .iter()
+            .any(|p| p.value().is_truthy(false));
+        Ok(ScopedJson::Derived(Value::Bool(result)))
+    }
+}
+
+/// Helper for unary logical NOT, truthy-evaluating its single parameter using
+/// Handlebars' own truthiness rules (see [`JsonTruthy`]).
+///
+/// ## Usage
+///
+/// ```handlebars
+/// {{#if (not isDisabled)}}
+///   <p>enabled</p>
+/// {{/if}}
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct NotHelper {}
+
+impl HelperDef for NotHelper {
+    fn call_inner<'reg: 'rc, 'rc>(
+        &self,
+        h: &Helper<'rc>,
+        _reg: &'reg Handlebars<'reg>,
+        _ctx: &'rc Context,
+        _rc: &mut RenderContext<'reg, 'rc>,
+    ) -> Result<ScopedJson<'reg, 'rc>, RenderError> {
+        let param = h.param(0).ok_or_else(|| {
+            RenderError::from(RenderErrorReason::ParamNotFoundForIndex("not", 0))
+        })?;
+
+        Ok(ScopedJson::Derived(Value::Bool(
+            !param.value().is_truthy(false),
+        )))
+    }
+}
+
+#[cfg(test)]
+mod comparison_helper_tests {
+    use super::*;
+    use serde_json::json;
+
+    fn registry() -> Handlebars<'static> {
+        let mut handlebars = Handlebars::new();
+        handlebars.register_helper("gt", Box::new(GtHelper {}));
+        handlebars.register_helper("gte", Box::new(GteHelper {}));
+        handlebars.register_helper("lt", Box::new(LtHelper {}));
+        handlebars.register_helper("lte", Box::new(LteHelper {}));
+        handlebars.register_helper("ne", Box::new(NeHelper {}));
+        handlebars.register_helper("and", Box::new(AndHelper {}));
+        handlebars.register_helper("or", Box::new(OrHelper {}));
+        handlebars.register_helper("not", Box::new(NotHelper {}));
+        handlebars
+    }
+
+    #[test]
+    fn gt_renders_main_block_when_greater() {
+        let handlebars = registry();
+        assert_eq!(
+            handlebars
+                .render_template("{{#gt 5 3}}yes{{else}}no{{/gt}}", &json!({}))
+                .unwrap(),
+            "yes"
+        );
+    }
+
+    #[test]
+    fn lt_compares_strings_lexically() {
+        let handlebars = registry();
+        assert_eq!(
+            handlebars
+                .render_template("{{#lt \"apple\" \"banana\"}}yes{{else}}no{{/lt}}", &json!({}))
+                .unwrap(),
+            "yes"
+        );
+    }
+
+    #[test]
+    fn gt_errors_on_uncomparable_types() {
+        let handlebars = registry();
+        assert!(handlebars
+            .render_template("{{#gt 1 \"a\"}}yes{{/gt}}", &json!({}))
+            .is_err());
+    }
+
+    #[test]
+    fn ne_usable_as_subexpression() {
+        let handlebars = registry();
+        assert_eq!(
+            handlebars
+                .render_template("{{#if (ne 1 2)}}yes{{else}}no{{/if}}", &json!({}))
+                .unwrap(),
+            "yes"
+        );
+    }
+
+    #[test]
+    fn and_requires_every_param_truthy() {
+        let handlebars = registry();
+        assert_eq!(
+            handlebars
+                .render_template(
+                    "{{#if (and (gt 5 3) (lt 5 9))}}yes{{else}}no{{/if}}",
+                    &json!({})
+                )
+                .unwrap(),
+            "yes"
+        );
+        assert_eq!(
+            handlebars
+                .render_template(
+                    "{{#if (and (gt 5 3) (lt 5 4))}}yes{{else}}no{{/if}}",
+                    &json!({})
+                )
+                .unwrap(),
+            "no"
+        );
+    }
+
+    #[test]
+    fn or_requires_any_param_truthy() {
+        let handlebars = registry();
+        assert_eq!(
+            handlebars
+                .render_template(
+                    "{{#if (or (gt 1 3) (lt 5 9))}}yes{{else}}no{{/if}}",
+                    &json!({})
+                )
+                .unwrap(),
+            "yes"
+        );
+    }
+
+    #[test]
+    fn not_negates_truthiness() {
+        let handlebars = registry();
+        assert_eq!(
+            handlebars
+                .render_template("{{#if (not false)}}yes{{else}}no{{/if}}", &json!({}))
+                .unwrap(),
+            "yes"
+        );
+    }
+}
+
+/// Walks a dotted/indexed path (e.g. `"user.address.city"` or
+/// `"items.0.name"`) into a JSON value, returning `None` if any segment is
+/// missing or the wrong kind (object key vs. array index) for the value it
+/// applies to.
+fn json_path_get<'v>(value: &'v Value, path: &str) -> Option<&'v Value> {
+    path.split('.').try_fold(value, |current, segment| match current {
+        Value::Object(map) => map.get(segment),
+        Value::Array(items) => segment.parse::<usize>().ok().and_then(|i| items.get(i)),
+        _ => None,
+    })
 }
 
 /// Helper to serialize data to a JSON string.
@@ -255,6 +809,9 @@ mod unless_equals_tests {
 /// <script type="application/json">
 ///   {{json data indent=2}}
 /// </script>
+///
+/// {{json data path="user.address.city"}}
+/// {{json data pretty=true}}
 /// ```
 ///
 /// ## Parameters
@@ -266,6 +823,13 @@ mod unless_equals_tests {
 /// * `indent`: Optional. If provided, the JSON output will be pretty-printed
 ///   with the specified indent level (integer).  If not provided, the JSON
 ///   output will be compact (no whitespace).
+/// * `pretty`: Optional. If `true`, pretty-prints with the default two-space
+///   indent, independent of `indent`. Useful when authors want pretty output
+///   without specifying an indent level.
+/// * `path`: Optional. A dotted/indexed path (e.g. `"user.address.city"` or
+///   `"items.0.name"`) walked into `data` before serializing. If any segment
+///   of the path is missing, this helper renders an empty string rather than
+///   raising an error.
 ///
 /// This helper is useful for embedding JSON data directly into templates,
 /// for example, to pass configuration or data to client-side JavaScript code.
@@ -289,12 +853,26 @@ impl HelperDef for JsonHelper {
             }
         };
 
-        let indent_param = h.hash_get("indent");
-        let use_pretty = indent_param.is_some();
+        let value = match h.hash_get("path").and_then(|p| p.value().as_str()) {
+            Some(path) => match json_path_get(param, path) {
+                Some(value) => value,
+                None => {
+                    out.write("")?;
+                    return Ok(());
+                }
+            },
+            None => param,
+        };
+
+        let use_pretty = h.hash_get("indent").is_some()
+            || h
+                .hash_get("pretty")
+                .map(|p| p.value().as_bool().unwrap_or(false))
+                .unwrap_or(false);
         let result = if use_pretty {
-            serde_json::to_string_pretty(param)
+            serde_json::to_string_pretty(value)
         } else {
-            serde_json::to_string(param)
+            serde_json::to_string(value)
         };
         let json_str = result.unwrap_or_else(|_| "{}".to_string());
         out.write(&json_str)?;
@@ -376,4 +954,228 @@ mod json_tests {
             .unwrap();
         assert_eq!(rendered_empty, "{}");
     }
+
+    #[test]
+    fn path_extracts_nested_field() {
+        let mut handlebars = Handlebars::new();
+        handlebars.register_helper("json", Box::new(JsonHelper {}));
+
+        let data = json!({"user": {"address": {"city": "Springfield"}}});
+        let rendered = handlebars
+            .render_template("{{json this path=\"user.address.city\"}}", &data)
+            .unwrap();
+        assert_eq!(rendered, "\"Springfield\"");
+    }
+
+    #[test]
+    fn path_indexes_into_arrays() {
+        let mut handlebars = Handlebars::new();
+        handlebars.register_helper("json", Box::new(JsonHelper {}));
+
+        let data = json!({"items": [{"name": "first"}, {"name": "second"}]});
+        let rendered = handlebars
+            .render_template("{{json this path=\"items.1.name\"}}", &data)
+            .unwrap();
+        assert_eq!(rendered, "\"second\"");
+    }
+
+    #[test]
+    fn path_missing_segment_renders_empty_string() {
+        let mut handlebars = Handlebars::new();
+        handlebars.register_helper("json", Box::new(JsonHelper {}));
+
+        let data = json!({"user": {}});
+        let rendered = handlebars
+            .render_template("{{json this path=\"user.address.city\"}}", &data)
+            .unwrap();
+        assert_eq!(rendered, "");
+    }
+
+    #[test]
+    fn pretty_flag_pretty_prints_without_indent() {
+        let mut handlebars = Handlebars::new();
+        handlebars.register_helper("json", Box::new(JsonHelper {}));
+
+        let data = json!({"a": 1});
+        let rendered = handlebars
+            .render_template("{{json this pretty=true}}", &data)
+            .unwrap();
+        assert_eq!(rendered, "{\n  \"a\": 1\n}");
+    }
+}
+
+/// Inverse of [`JsonHelper`]: parses a JSON string param back into a value
+/// usable as a subexpression result.
+///
+/// ## Usage
+///
+/// ```handlebars
+/// {{#with (parseJson jsonString)}}
+///   {{name}}
+/// {{/with}}
+/// ```
+///
+/// ## Parameters
+///
+/// * `json_str`: The JSON-encoded string to parse.
+///
+/// Raises a `RenderError` if the parameter is not a string or is not valid
+/// JSON.
+#[derive(Clone, Copy, Debug)]
+pub struct ParseJsonHelper {}
+
+impl HelperDef for ParseJsonHelper {
+    fn call_inner<'reg: 'rc, 'rc>(
+        &self,
+        h: &Helper<'rc>,
+        _reg: &'reg Handlebars<'reg>,
+        _ctx: &'rc Context,
+        _rc: &mut RenderContext<'reg, 'rc>,
+    ) -> Result<ScopedJson<'reg, 'rc>, RenderError> {
+        let raw = h
+            .param(0)
+            .and_then(|p| p.value().as_str())
+            .ok_or_else(|| {
+                RenderError::from(RenderErrorReason::ParamNotFoundForIndex("parseJson", 0))
+            })?;
+
+        let parsed: Value = serde_json::from_str(raw).map_err(|e| {
+            RenderError::from(RenderErrorReason::Other(format!(
+                "parseJson: invalid JSON: {}",
+                e
+            )))
+        })?;
+
+        Ok(ScopedJson::Derived(parsed))
+    }
+}
+
+#[cfg(test)]
+mod parse_json_tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn parses_object_usable_as_subexpression() {
+        let mut handlebars = Handlebars::new();
+        handlebars.register_helper("parseJson", Box::new(ParseJsonHelper {}));
+
+        let rendered = handlebars
+            .render_template(
+                "{{#with (parseJson \"{\\\"name\\\":\\\"Ada\\\"}\")}}{{name}}{{/with}}",
+                &json!({}),
+            )
+            .unwrap();
+        assert_eq!(rendered, "Ada");
+    }
+
+    #[test]
+    fn invalid_json_is_a_render_error() {
+        let mut handlebars = Handlebars::new();
+        handlebars.register_helper("parseJson", Box::new(ParseJsonHelper {}));
+
+        assert!(handlebars
+            .render_template("{{parseJson \"not json\"}}", &json!({}))
+            .is_err());
+    }
+}
+
+/// Helper to emit render-time diagnostics through the `log` crate without
+/// writing anything to the template output.
+///
+/// ## Usage
+///
+/// ```handlebars
+/// {{log someValue}}
+/// {{log "processing user" user.name level="warn"}}
+/// ```
+///
+/// ## Parameters
+///
+/// * One or more values to log. String params are logged as-is; any other
+///   value is serialized to JSON. Multiple params are joined with a space.
+///
+/// ## Hash Arguments
+///
+/// * `level`: Optional. One of `trace`, `debug`, `info`, `warn`, `error`
+///   (case-insensitive). Defaults to `info`.
+///
+/// This lets authors instrument templates and inspect intermediate context
+/// during rendering without mutating the rendered output.
+#[derive(Clone, Copy, Debug)]
+pub struct LogHelper {}
+
+impl HelperDef for LogHelper {
+    fn call<'reg: 'rc, 'rc>(
+        &self,
+        h: &Helper<'rc>,
+        _reg: &'reg Handlebars<'reg>,
+        _ctx: &'rc Context,
+        _rc: &mut RenderContext<'reg, 'rc>,
+        _out: &mut dyn Output,
+    ) -> Result<(), RenderError> {
+        let level = h
+            .hash_get("level")
+            .and_then(|p| p.value().as_str())
+            .and_then(|s| Level::from_str(s).ok())
+            .unwrap_or(Level::Info);
+
+        let message = h
+            .params()
+            .iter()
+            .map(|p| match p.value() {
+                Value::String(s) => s.clone(),
+                other => serde_json::to_string(other).unwrap_or_default(),
+            })
+            .collect::<Vec<_>>()
+            .join(" ");
+
+        log::log!(level, "{}", message);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod log_helper_tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn writes_nothing_to_output() {
+        let mut handlebars = Handlebars::new();
+        handlebars.register_helper("log", Box::new(LogHelper {}));
+
+        assert_eq!(
+            handlebars
+                .render_template("before{{log \"hello\"}}after", &json!({}))
+                .unwrap(),
+            "beforeafter"
+        );
+    }
+
+    #[test]
+    fn unknown_level_falls_back_to_info() {
+        let mut handlebars = Handlebars::new();
+        handlebars.register_helper("log", Box::new(LogHelper {}));
+
+        assert_eq!(
+            handlebars
+                .render_template("{{log \"x\" level=\"not-a-level\"}}", &json!({}))
+                .unwrap(),
+            ""
+        );
+    }
+
+    #[test]
+    fn multiple_params_are_joined() {
+        let mut handlebars = Handlebars::new();
+        handlebars.register_helper("log", Box::new(LogHelper {}));
+
+        assert_eq!(
+            handlebars
+                .render_template("{{log \"a\" \"b\" level=\"debug\"}}", &json!({}))
+                .unwrap(),
+            ""
+        );
+    }
 }
@@ -15,17 +15,23 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use handlebars::{
-    Context, Handlebars, Helper, HelperDef, Output, RenderContext, RenderError, RenderErrorReason,
-    Renderable,
+    Context, Decorator, DecoratorDef, Handlebars, Helper, HelperDef, Output, RenderContext,
+    RenderError, RenderErrorReason, Renderable, Template,
 };
 use pyo3::exceptions::{PyFileNotFoundError, PyValueError};
 use pyo3::prelude::*;
+use pyo3::types::PyBytes;
 use pyo3::wrap_pyfunction;
 use serde_json::Value;
 use std::collections::HashMap;
+use std::io;
 use std::path::Path;
+use std::sync::{Arc, Mutex};
+use walkdir::WalkDir;
 
 mod helpers;
+#[cfg(feature = "scripting")]
+mod scripting;
 
 /// Python bindings for the handlebars-rust library.
 ///
@@ -39,6 +45,7 @@ mod helpers;
 #[pymodule]
 fn _native(py: Python<'_>, m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<HandlebarrzHelperOptions>()?;
+    m.add_class::<HandlebarrzDecoratorOptions>()?;
     m.add_class::<HandlebarrzTemplate>()?;
     m.add_function(wrap_pyfunction!(html_escape, py)?)?;
     m.add_function(wrap_pyfunction!(no_escape, py)?)?;
@@ -169,6 +176,76 @@ impl HandlebarrzHelperOptions {
     }
 }
 
+/// Handlebars decorator options Python wrapper.
+///
+/// WARNING: only intended to be used within the Python::with_gil(...) scope and not stored across threads.
+#[pyclass(unsendable)]
+pub struct HandlebarrzDecoratorOptions {
+    decorator_ptr: *const Decorator<'static>,
+    ctx_ptr: *const Context,
+    rc_ptr: *mut RenderContext<'static, 'static>,
+}
+
+#[pymethods]
+impl HandlebarrzDecoratorOptions {
+    #[new]
+    fn new() -> Self {
+        Self {
+            decorator_ptr: std::ptr::null(),
+            ctx_ptr: std::ptr::null(),
+            rc_ptr: std::ptr::null_mut(),
+        }
+    }
+
+    /// Returns JSON representation of a context.
+    #[pyo3(text_signature = "($self)")]
+    pub fn context_json(&self) -> PyResult<String> {
+        let ctx = unsafe { &*self.ctx_ptr };
+        serde_json::to_string(ctx.data())
+            .map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))
+    }
+
+    /// Returns hash JSON value for a given key (resolved within the context).
+    #[pyo3(text_signature = "($self, key)")]
+    pub fn hash_value_json(&self, key: &str) -> PyResult<String> {
+        let decorator = unsafe { &*self.decorator_ptr };
+        if let Some(path_and_json) = decorator.hash_get(key) {
+            let value = path_and_json.value();
+            serde_json::to_string(value)
+                .map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))
+        } else {
+            Ok(String::new())
+        }
+    }
+
+    /// Sets an `@`-prefixed local variable in the render context, making it
+    /// visible to the rest of the template as `{{@key}}`. This is the
+    /// mutation path that lets a custom decorator actually affect rendering
+    /// instead of only observing it.
+    #[pyo3(text_signature = "($self, key, value_json)")]
+    pub fn set_local_var(&self, key: &str, value_json: &str) -> PyResult<()> {
+        let value: Value = serde_json::from_str(value_json)
+            .map_err(|e| pyo3::exceptions::PyValueError::new_err(format!("invalid JSON: {}", e)))?;
+        let rc = unsafe { &mut *self.rc_ptr };
+        rc.set_local_var(key, value);
+        Ok(())
+    }
+
+    /// Registers the decorator's own block content as a partial under
+    /// `name`, implementing the `{{#*inline "name"}}...{{/inline}}` idiom
+    /// (the built-in `inline` decorator does exactly this) for custom
+    /// decorators. A no-op if the decorator was not invoked as a block.
+    #[pyo3(text_signature = "($self, name)")]
+    pub fn register_partial_from_template(&self, name: &str) -> PyResult<()> {
+        let decorator = unsafe { &*self.decorator_ptr };
+        if let Some(template) = decorator.template() {
+            let rc = unsafe { &mut *self.rc_ptr };
+            rc.set_partial(name.to_string(), std::borrow::Cow::Owned(template.clone()));
+        }
+        Ok(())
+    }
+}
+
 /// Callable helper.
 struct PyHelperDef {
     func: PyObject,
@@ -232,6 +309,112 @@ impl HelperDef for PyHelperDef {
     }
 }
 
+/// Callable decorator.
+struct PyDecoratorDef {
+    func: PyObject,
+}
+
+impl DecoratorDef for PyDecoratorDef {
+    fn call<'reg: 'rc, 'rc>(
+        &self,
+        d: &Decorator<'rc>,
+        _reg: &'reg Handlebars<'reg>,
+        ctx: &'rc Context,
+        rc: &mut RenderContext<'reg, 'rc>,
+    ) -> Result<(), RenderError> {
+        Python::with_gil(|py| {
+            // Extract params.
+            let params: Vec<&Value> = d.params().iter().map(|p| p.value()).collect();
+            let params_json = match serde_json::to_string(&params) {
+                Ok(json) => json,
+                Err(e) => {
+                    let desc = format!("Failed to serialize params: {}", e);
+                    return Err(RenderError::from(RenderErrorReason::Other(desc)));
+                }
+            };
+
+            // Create decorator context options. This hands the Python
+            // callable a real mutation path (`set_local_var`,
+            // `register_partial_from_template`) into the same
+            // `RenderContext` the rest of the template renders against.
+            let py_options = HandlebarrzDecoratorOptions {
+                decorator_ptr: d as *const _ as *const _,
+                ctx_ptr: ctx as *const _,
+                rc_ptr: rc as *mut _ as *mut _,
+            };
+            let py_options_obj = Py::new(py, py_options).map_err(|e| {
+                RenderError::from(RenderErrorReason::Other(format!(
+                    "Failed to create HandlebarrzDecoratorOptions: {}",
+                    e
+                )))
+            })?;
+
+            // Call Python function.
+            let result = self.func.call1(py, (params_json, py_options_obj));
+
+            match result {
+                Ok(_) => Ok(()),
+                Err(e) => {
+                    let desc = format!("Decorator execution failed: {}", e);
+                    Err(RenderError::from(RenderErrorReason::Other(desc)))
+                }
+            }
+        })
+    }
+}
+
+/// Adapts a Python file-like object (anything with a `write` method) into a
+/// Rust `std::io::Write`, so handlebars can stream rendered output to it via
+/// `render_to_write` instead of buffering the whole template in memory.
+struct PyWriteAdapter {
+    writer: PyObject,
+}
+
+impl io::Write for PyWriteAdapter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        Python::with_gil(|py| {
+            let bytes = PyBytes::new_bound(py, buf);
+            self.writer
+                .call_method1(py, "write", (bytes,))
+                .map(|_| buf.len())
+                .map_err(|e| io::Error::new(io::ErrorKind::Other, e.to_string()))
+        })
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Python::with_gil(|py| match self.writer.call_method0(py, "flush") {
+            Ok(_) => Ok(()),
+            Err(e) if e.is_instance_of::<pyo3::exceptions::PyAttributeError>(py) => Ok(()),
+            Err(e) => Err(io::Error::new(io::ErrorKind::Other, e.to_string())),
+        })
+    }
+}
+
+/// Renders a compiled template, seeding `@`-prefixed local variables from
+/// `options` into the render context before rendering.
+///
+/// This is how `render_template`/`render_with_options` thread request-scoped
+/// metadata (model name, temperature, user id, ...) into a template as
+/// `{{@key}}`, matching how Genkit/dotprompt passes `@`-context, without
+/// polluting the data object that `{{key}}` resolves against.
+fn render_with_locals(
+    registry: &Handlebars<'_>,
+    tpl: &Template,
+    data: &Value,
+    options: Option<&Value>,
+) -> Result<String, RenderError> {
+    let ctx = Context::wraps(data)?;
+    let mut rc = RenderContext::new(None);
+
+    if let Some(Value::Object(options_map)) = options {
+        for (key, value) in options_map {
+            rc.set_local_var(key, value.clone());
+        }
+    }
+
+    tpl.renders(registry, &ctx, &mut rc)
+}
+
 /// A Handlebars template engine instance.
 ///
 /// This class provides methods for:
@@ -262,6 +445,23 @@ impl HelperDef for PyHelperDef {
 struct HandlebarrzTemplate {
     registry: Handlebars<'static>,
     py_helpers: HashMap<String, PyObject>,
+    /// Set by a `set_escape_fn_callable` closure when the Python escape
+    /// function it wraps raises or returns a non-string value. `EscapeFn`
+    /// has no channel to return a `Result`, so the closure records the
+    /// failure here instead, and every render method checks and clears it
+    /// after rendering, raising `PyValueError` rather than silently
+    /// swallowing the failure.
+    escape_error: Arc<Mutex<Option<String>>>,
+}
+
+impl HandlebarrzTemplate {
+    /// Takes (and clears) any failure recorded by a `set_escape_fn_callable`
+    /// closure during the most recent render call, so each render method can
+    /// surface it as a `PyValueError` instead of silently returning output
+    /// escaped by the wrong scheme.
+    fn take_escape_error(&self) -> Option<String> {
+        self.escape_error.lock().unwrap().take()
+    }
 }
 
 #[pymethods]
@@ -278,6 +478,7 @@ impl HandlebarrzTemplate {
         Self {
             registry,
             py_helpers: HashMap::new(),
+            escape_error: Arc::new(Mutex::new(None)),
         }
     }
 
@@ -369,6 +570,60 @@ impl HandlebarrzTemplate {
         Ok(())
     }
 
+    /// Sets an arbitrary Python callable as the escape function.
+    ///
+    /// Unlike [`HandlebarrzTemplate::set_escape_fn`], which only accepts the
+    /// two built-in escapers by name, this registers a closure that calls
+    /// back into Python for every escaped value. This lets users implement
+    /// domain-specific escaping (e.g. JSON-string escaping, shell-argument
+    /// quoting, or YAML-safe encoding) without forking the crate.
+    ///
+    /// # Arguments
+    ///
+    /// * `escape_fn` - A Python callable taking the raw string and returning
+    ///   the escaped string.
+    ///
+    /// # Returns
+    ///
+    /// `None`
+    ///
+    /// # Raises
+    ///
+    /// Nothing at registration time. If, at render time, `escape_fn` raises
+    /// an exception or returns a non-string value, the underlying
+    /// handlebars `EscapeFn` signature has no channel to propagate a
+    /// `RenderError` from within the escape call itself, so the failure is
+    /// instead recorded and every `render*` method raises `PyValueError`
+    /// once rendering returns. The render result in that case must not be
+    /// trusted: rather than silently falling back to a different escaping
+    /// scheme (e.g. `html_escape` for a caller expecting shell-quoting),
+    /// this surfaces the failure so it cannot be mistaken for correctly
+    /// escaped output.
+    #[pyo3(text_signature = "($self, escape_fn)")]
+    fn set_escape_fn_callable(&mut self, escape_fn: PyObject) -> PyResult<()> {
+        let escape_error = Arc::clone(&self.escape_error);
+        self.registry.register_escape_fn(move |text: &str| -> String {
+            Python::with_gil(|py| match escape_fn.call1(py, (text,)) {
+                Ok(result) => match result.extract::<String>(py) {
+                    Ok(s) => s,
+                    Err(e) => {
+                        *escape_error.lock().unwrap() = Some(format!(
+                            "escape_fn_callable returned a non-string value: {}",
+                            e
+                        ));
+                        text.to_string()
+                    }
+                },
+                Err(e) => {
+                    *escape_error.lock().unwrap() =
+                        Some(format!("escape_fn_callable raised an exception: {}", e));
+                    text.to_string()
+                }
+            })
+        });
+        Ok(())
+    }
+
     /// Registers a template with the given name.
     ///
     /// # Arguments
@@ -441,6 +696,69 @@ impl HandlebarrzTemplate {
             .map_err(|e| PyValueError::new_err(e.to_string()))
     }
 
+    /// Registers every template file under a directory, recursively.
+    ///
+    /// This walks `dir_path` (using `walkdir`, matching the upstream
+    /// `dir_source` feature) and registers every file whose name ends with
+    /// `extension`. A template's name is derived from its path relative to
+    /// `dir_path`, with the extension stripped and path separators
+    /// normalized to `/` (so `partials/header.hbs` registers as
+    /// `partials/header`).
+    ///
+    /// # Arguments
+    ///
+    /// * `dir_path` - The directory to walk.
+    /// * `extension` - The file extension (without the leading dot)
+    ///   identifying template files, e.g. `"hbs"`.
+    ///
+    /// # Returns
+    ///
+    /// `None`
+    ///
+    /// # Raises
+    ///
+    /// `PyFileNotFoundError` if `dir_path` does not exist.
+    /// `PyValueError` wrapping the first template that fails to compile.
+    #[pyo3(text_signature = "($self, dir_path, extension)")]
+    fn register_templates_directory(&mut self, dir_path: &str, extension: &str) -> PyResult<()> {
+        let root = Path::new(dir_path);
+        if !root.exists() {
+            return Err(PyFileNotFoundError::new_err(format!(
+                "Template directory not found: {}",
+                dir_path
+            )));
+        }
+
+        let suffix = format!(".{}", extension);
+        for entry in WalkDir::new(root).into_iter().filter_map(|e| e.ok()) {
+            let path = entry.path();
+            if !path.is_file() {
+                continue;
+            }
+            let matches_extension = path
+                .file_name()
+                .and_then(|n| n.to_str())
+                .map(|name| name.ends_with(&suffix))
+                .unwrap_or(false);
+            if !matches_extension {
+                continue;
+            }
+
+            let relative = path.strip_prefix(root).unwrap_or(path).with_extension("");
+            let name = relative
+                .components()
+                .map(|c| c.as_os_str().to_string_lossy().into_owned())
+                .collect::<Vec<_>>()
+                .join("/");
+
+            self.registry
+                .register_template_file(&name, path)
+                .map_err(|e| PyValueError::new_err(format!("{}: {}", name, e)))?;
+        }
+
+        Ok(())
+    }
+
     /// Registers a helper function with the given name.
     ///
     /// # Arguments
@@ -465,6 +783,101 @@ impl HandlebarrzTemplate {
         Ok(())
     }
 
+    /// Registers a decorator function with the given name.
+    ///
+    /// Decorators are the handlebars mechanism behind constructs like
+    /// `{{#*inline "name"}}...{{/inline}}`: unlike helpers, they don't
+    /// produce output directly but can mutate the render context (for
+    /// example, registering a local partial or setting a local variable)
+    /// before the rest of the template renders. The decorator function
+    /// receives a `HandlebarrzDecoratorOptions` with `set_local_var` and
+    /// `register_partial_from_template` methods for exactly this purpose.
+    /// The built-in `inline` decorator is registered automatically on every
+    /// new `HandlebarrzTemplate`, so authors can already factor shared
+    /// snippets with `{{#*inline}}` without calling this method; use it to
+    /// add custom decorators of your own.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the decorator.
+    /// * `decorator_fn` - The Python function to use as the decorator.
+    ///
+    /// # Returns
+    ///
+    /// `None`
+    #[pyo3(text_signature = "($self, name, decorator_fn)")]
+    fn register_decorator(&mut self, name: &str, decorator_fn: PyObject) -> PyResult<()> {
+        let decorator = PyDecoratorDef { func: decorator_fn };
+        self.registry.register_decorator(name, Box::new(decorator));
+        Ok(())
+    }
+
+    /// Registers a rhai script as a helper, compiled once up front.
+    ///
+    /// Every Python helper registered via `register_helper` re-enters the
+    /// interpreter through `Python::with_gil` on each invocation, which is
+    /// slow for helpers called many times in a loop. A script helper has no
+    /// such overhead: the script is compiled once here and evaluated
+    /// directly by the Rhai engine at render time, with no Python call.
+    ///
+    /// The script sees its positional params as the Rhai array `params`, its
+    /// hash arguments as the Rhai map `hash`, and the current render context
+    /// as `ctx`.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the helper.
+    /// * `script` - The Rhai script source.
+    ///
+    /// # Returns
+    ///
+    /// `None`
+    ///
+    /// # Raises
+    ///
+    /// `PyValueError` if the script fails to compile.
+    #[cfg(feature = "scripting")]
+    #[pyo3(text_signature = "($self, name, script)")]
+    fn register_script_helper(&mut self, name: &str, script: &str) -> PyResult<()> {
+        let helper =
+            scripting::CompiledScriptHelper::compile(script).map_err(PyValueError::new_err)?;
+        self.registry.register_helper(name, Box::new(helper));
+        Ok(())
+    }
+
+    /// Registers a rhai script read from a file as a helper.
+    ///
+    /// See [`HandlebarrzTemplate::register_script_helper`] for how the
+    /// script can access its params, hash, and the render context.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the helper.
+    /// * `path` - The path to the Rhai script file.
+    ///
+    /// # Returns
+    ///
+    /// `None`
+    ///
+    /// # Raises
+    ///
+    /// `PyFileNotFoundError` if the script file does not exist.
+    /// `PyValueError` if the script cannot be read or fails to compile.
+    #[cfg(feature = "scripting")]
+    #[pyo3(text_signature = "($self, name, path)")]
+    fn register_script_helper_file(&mut self, name: &str, path: &str) -> PyResult<()> {
+        if !Path::new(path).exists() {
+            return Err(PyFileNotFoundError::new_err(format!(
+                "Script file not found: {}",
+                path
+            )));
+        }
+
+        let script = std::fs::read_to_string(path)
+            .map_err(|e| PyValueError::new_err(format!("Failed to read script file: {}", e)))?;
+        self.register_script_helper(name, &script)
+    }
+
     /// Unregisters a template with the given name.
     ///
     /// # Arguments
@@ -513,9 +926,15 @@ impl HandlebarrzTemplate {
         let data: Value = serde_json::from_str(data)
             .map_err(|e| PyValueError::new_err(format!("invalid JSON: {}", e)))?;
 
-        self.registry
+        let result = self
+            .registry
             .render(name, &data)
-            .map_err(|e| PyValueError::new_err(e.to_string()))
+            .map_err(|e| PyValueError::new_err(e.to_string()));
+
+        if let Some(err) = self.take_escape_error() {
+            return Err(PyValueError::new_err(err));
+        }
+        result
     }
 
     /// Renders a template string directly without registering.
@@ -524,7 +943,10 @@ impl HandlebarrzTemplate {
     ///
     /// * `template_string` - The template source code.
     /// * `data_json` - The data to use for rendering (as JSON).
-    /// * `options_json` - Optional. If provided, the data will be merged with this JSON object.
+    /// * `options_json` - Optional. If provided, each top-level key of this
+    ///   JSON object is exposed to the template as an `@`-prefixed local
+    ///   variable (`{{@key}}`), distinct from `data_json` so it can't
+    ///   collide with user data.
     ///
     /// # Raises
     ///
@@ -538,26 +960,104 @@ impl HandlebarrzTemplate {
         &self,
         template_string: &str,
         data_json: &str,
-        _options_json: Option<&str>,
+        options_json: Option<&str>,
     ) -> PyResult<String> {
         let data: Value = serde_json::from_str(data_json)
             .map_err(|e| PyValueError::new_err(format!("invalid JSON: {}", e)))?;
+        let options: Option<Value> = options_json
+            .map(serde_json::from_str)
+            .transpose()
+            .map_err(|e| PyValueError::new_err(format!("invalid options JSON: {}", e)))?;
 
-        // TODO: Implement setting the data attribute of runtime options.
-        // if let Some(options_str) = options_json {
-        //     let options_data: Value = serde_json::from_str(options_str)
-        //         .map_err(|e| PyValueError::new_err(format!("invalid options JSON: {}", e)))?;
+        let tpl = Template::compile(template_string)
+            .map_err(|e| PyValueError::new_err(e.to_string()))?;
 
-        //     if let (Some(data_map), Some(_options_map)) =
-        //         (data.as_object_mut(), options_data.as_object())
-        //     {
-        //         data_map.insert("@data".to_string(), options_data.clone());
-        //     }
-        // }
+        let result = render_with_locals(&self.registry, &tpl, &data, options.as_ref())
+            .map_err(|e| PyValueError::new_err(e.to_string()));
 
-        self.registry
-            .render_template(template_string, &data)
-            .map_err(|e| PyValueError::new_err(e.to_string()))
+        if let Some(err) = self.take_escape_error() {
+            return Err(PyValueError::new_err(err));
+        }
+        result
+    }
+
+    /// Renders a registered template with `@`-prefixed runtime options.
+    ///
+    /// See [`HandlebarrzTemplate::render_template`] for how `options_json`
+    /// becomes `{{@key}}` inside the template.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the registered template.
+    /// * `data` - The data to use for rendering (as JSON).
+    /// * `options_json` - A JSON object whose keys become `@`-prefixed local
+    ///   variables.
+    ///
+    /// # Returns
+    ///
+    /// Rendered template as a string.
+    ///
+    /// # Raises
+    ///
+    /// `PyValueError` if the template is not registered, `data`/`options_json`
+    /// are not valid JSON, or the template cannot be rendered.
+    #[pyo3(text_signature = "($self, name, data, options_json)")]
+    fn render_with_options(&self, name: &str, data: &str, options_json: &str) -> PyResult<String> {
+        let data: Value = serde_json::from_str(data)
+            .map_err(|e| PyValueError::new_err(format!("invalid JSON: {}", e)))?;
+        let options: Value = serde_json::from_str(options_json)
+            .map_err(|e| PyValueError::new_err(format!("invalid options JSON: {}", e)))?;
+
+        let tpl = self
+            .registry
+            .get_template(name)
+            .ok_or_else(|| PyValueError::new_err(format!("Template not found: {}", name)))?;
+
+        let result = render_with_locals(&self.registry, tpl, &data, Some(&options))
+            .map_err(|e| PyValueError::new_err(e.to_string()));
+
+        if let Some(err) = self.take_escape_error() {
+            return Err(PyValueError::new_err(err));
+        }
+        result
+    }
+
+    /// Renders a registered template, streaming output into a Python
+    /// file-like object instead of building it up as a `String`.
+    ///
+    /// This drives handlebars' `render_to_write` path, so bytes are flushed
+    /// to `writer` incrementally as each template element is rendered
+    /// rather than doubling peak memory for large, multi-megabyte renders.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the template.
+    /// * `data` - The data to use for rendering (as JSON).
+    /// * `writer` - A Python object exposing a `write(bytes)` method (and
+    ///   optionally `flush()`), e.g. an open file or socket.
+    ///
+    /// # Returns
+    ///
+    /// `None`
+    ///
+    /// # Raises
+    ///
+    /// `PyValueError` if the template cannot be rendered, or if `writer`
+    /// raises while being written to.
+    #[pyo3(text_signature = "($self, name, data, writer)")]
+    fn render_to_writer(&self, name: &str, data: &str, writer: PyObject) -> PyResult<()> {
+        let data: Value = serde_json::from_str(data)
+            .map_err(|e| PyValueError::new_err(format!("invalid JSON: {}", e)))?;
+
+        let result = self
+            .registry
+            .render_to_write(name, &data, PyWriteAdapter { writer })
+            .map_err(|e| PyValueError::new_err(e.to_string()));
+
+        if let Some(err) = self.take_escape_error() {
+            return Err(PyValueError::new_err(err));
+        }
+        result
     }
 
     /// Registers the extra helper functions.
@@ -567,6 +1067,11 @@ impl HandlebarrzTemplate {
     /// - `ifEquals`
     /// - `unlessEquals`
     /// - `json`
+    /// - `parseJson`
+    /// - `log`
+    /// - `gt`, `gte`, `lt`, `lte`, `ne`
+    /// - `and`, `or`, `not`
+    /// - `script` (only when the `scripting` cargo feature is enabled)
     ///
     /// # Returns
     ///
@@ -579,6 +1084,29 @@ impl HandlebarrzTemplate {
             .register_helper("unlessEquals", Box::new(helpers::UnlessEqualsHelper {}));
         self.registry
             .register_helper("json", Box::new(helpers::JsonHelper {}));
+        self.registry
+            .register_helper("parseJson", Box::new(helpers::ParseJsonHelper {}));
+        self.registry
+            .register_helper("log", Box::new(helpers::LogHelper {}));
+        self.registry
+            .register_helper("gt", Box::new(helpers::GtHelper {}));
+        self.registry
+            .register_helper("gte", Box::new(helpers::GteHelper {}));
+        self.registry
+            .register_helper("lt", Box::new(helpers::LtHelper {}));
+        self.registry
+            .register_helper("lte", Box::new(helpers::LteHelper {}));
+        self.registry
+            .register_helper("ne", Box::new(helpers::NeHelper {}));
+        self.registry
+            .register_helper("and", Box::new(helpers::AndHelper {}));
+        self.registry
+            .register_helper("or", Box::new(helpers::OrHelper {}));
+        self.registry
+            .register_helper("not", Box::new(helpers::NotHelper {}));
+        #[cfg(feature = "scripting")]
+        self.registry
+            .register_helper("script", Box::new(scripting::ScriptHelper {}));
         Ok(())
     }
 }
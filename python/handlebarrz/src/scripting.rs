@@ -0,0 +1,338 @@
+// Copyright 2025 Google LLC
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// SPDX-License-Identifier: Apache-2.0
+
+//! Rhai-backed scripting helpers, gated behind the `scripting` cargo feature.
+//!
+//! These helpers let prompt authors express small computations (token
+//! budget math, clamping, string munging) directly in a template without
+//! reaching for a full Python/Rust helper, following the approach of
+//! handlebars-rust's own `scripting.rs` example.
+
+use handlebars::{
+    Context, Handlebars, Helper, HelperDef, JsonTruthy, Output, RenderContext, RenderError,
+    RenderErrorReason, Renderable,
+};
+use rhai::{Dynamic, Engine, Scope, AST};
+use serde_json::Value;
+
+/// Maximum number of rhai operations allowed per script evaluation, to bound
+/// the cost of a single call.
+const MAX_OPERATIONS: u64 = 10_000;
+
+/// Maximum length (in bytes) of any string produced while evaluating a
+/// script, to prevent runaway allocations.
+const MAX_STRING_SIZE: usize = 1 << 16;
+
+/// Builds a `rhai::Engine` with `eval` disabled and operation/string-size
+/// limits applied, so an author's expression can't escape the sandbox or
+/// run away with resources.
+pub(crate) fn sandboxed_engine() -> Engine {
+    let mut engine = Engine::new();
+    engine.set_max_operations(MAX_OPERATIONS);
+    engine.set_max_string_size(MAX_STRING_SIZE);
+    engine.set_max_expr_depths(64, 64);
+    engine.disable_symbol("eval");
+    engine
+}
+
+/// Converts a `serde_json::Value` into a rhai `Dynamic`, the inverse of
+/// [`dynamic_to_value`].
+pub(crate) fn value_to_dynamic(value: &Value) -> Result<Dynamic, RenderError> {
+    rhai::serde::to_dynamic(value).map_err(|e| {
+        RenderError::from(RenderErrorReason::Other(format!(
+            "script: failed to convert value into rhai: {}",
+            e
+        )))
+    })
+}
+
+/// Converts a rhai `Dynamic` back into a `serde_json::Value`, the inverse of
+/// [`value_to_dynamic`].
+pub(crate) fn dynamic_to_value(dynamic: Dynamic) -> Result<Value, RenderError> {
+    rhai::serde::from_dynamic(&dynamic).map_err(|e| {
+        RenderError::from(RenderErrorReason::Other(format!(
+            "script: failed to convert rhai result into JSON: {}",
+            e
+        )))
+    })
+}
+
+/// Compiles a rhai expression, surfacing parse errors as `RenderError`.
+pub(crate) fn compile(engine: &Engine, expr: &str) -> Result<AST, RenderError> {
+    engine
+        .compile(expr)
+        .map_err(|e| RenderError::from(RenderErrorReason::Other(format!("script: {}", e))))
+}
+
+/// Runs a compiled script against a scope built from the current render
+/// context (exposed as `ctx`) plus the helper's hash arguments.
+fn eval(engine: &Engine, ast: &AST, ctx: &Context, h: &Helper<'_>) -> Result<Dynamic, RenderError> {
+    let mut scope = Scope::new();
+    scope.push("ctx", value_to_dynamic(ctx.data())?);
+    for (key, path_and_json) in h.hash() {
+        scope.push(key.to_string(), value_to_dynamic(path_and_json.value())?);
+    }
+
+    engine
+        .eval_ast_with_scope(&mut scope, ast)
+        .map_err(|e| RenderError::from(RenderErrorReason::Other(format!("script: {}", e))))
+}
+
+/// Helper evaluating a sandboxed rhai expression against the render context.
+///
+/// ## Usage
+///
+/// ```handlebars
+/// {{script "tokenBudget - used" tokenBudget=1000 used=usedTokens}}
+/// ```
+///
+/// Used as a block helper, it renders the main block when the script
+/// evaluates to a truthy value and the inverse block otherwise:
+///
+/// ```handlebars
+/// {{#script "used > tokenBudget * 0.9"}}
+///   <p>approaching the token budget</p>
+/// {{/script}}
+/// ```
+///
+/// ## Parameters
+///
+/// * `expr`: The rhai expression source to compile and evaluate.
+///
+/// ## Hash Arguments
+///
+/// Every hash argument is exposed to the script as a variable of the same
+/// name. The current render context (`this`) is additionally exposed as the
+/// `ctx` variable.
+///
+/// Compilation errors and runtime traps (including operations exceeding the
+/// sandboxed engine's limits) surface as `RenderError`.
+#[derive(Clone, Copy, Debug)]
+pub struct ScriptHelper {}
+
+impl HelperDef for ScriptHelper {
+    fn call<'reg: 'rc, 'rc>(
+        &self,
+        h: &Helper<'rc>,
+        reg: &'reg Handlebars<'reg>,
+        ctx: &'rc Context,
+        rc: &mut RenderContext<'reg, 'rc>,
+        out: &mut dyn Output,
+    ) -> Result<(), RenderError> {
+        let expr = h
+            .param(0)
+            .and_then(|p| p.value().as_str())
+            .ok_or_else(|| {
+                RenderError::from(RenderErrorReason::ParamNotFoundForIndex("script", 0))
+            })?;
+
+        let engine = sandboxed_engine();
+        let ast = compile(&engine, expr)?;
+        let result = eval(&engine, &ast, ctx, h)?;
+        let value = dynamic_to_value(result)?;
+
+        if h.is_block() {
+            if value.is_truthy(false) {
+                if let Some(template) = h.template() {
+                    template.render(reg, ctx, rc, out)?;
+                }
+            } else if let Some(template) = h.inverse() {
+                template.render(reg, ctx, rc, out)?;
+            }
+        } else {
+            let rendered = match &value {
+                Value::String(s) => s.clone(),
+                other => serde_json::to_string(other).unwrap_or_default(),
+            };
+            out.write(&rendered)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// A rhai script compiled once and registered as a named Handlebars helper.
+///
+/// Unlike [`ScriptHelper`], whose expression is a template parameter
+/// re-compiled on every call, a `CompiledScriptHelper` is compiled once at
+/// registration time (see `HandlebarrzTemplate::register_script_helper` in
+/// the crate's Python bindings). This avoids the per-call `Python::with_gil`
+/// overhead that Python-backed helpers pay, at the cost of the script only
+/// being able to see its params/hash/context, not calling back into Python.
+///
+/// The script sees its positional params as the rhai array `params`, its
+/// hash arguments as the rhai map `hash`, and the current render context as
+/// `ctx`.
+pub struct CompiledScriptHelper {
+    engine: Engine,
+    ast: AST,
+}
+
+impl CompiledScriptHelper {
+    /// Compiles `script` against a fresh sandboxed engine, returning a
+    /// human-readable error on a parse failure.
+    pub fn compile(script: &str) -> Result<Self, String> {
+        let engine = sandboxed_engine();
+        let ast = engine.compile(script).map_err(|e| e.to_string())?;
+        Ok(Self { engine, ast })
+    }
+}
+
+impl HelperDef for CompiledScriptHelper {
+    fn call<'reg: 'rc, 'rc>(
+        &self,
+        h: &Helper<'rc>,
+        _reg: &'reg Handlebars<'reg>,
+        ctx: &'rc Context,
+        _rc: &mut RenderContext<'reg, 'rc>,
+        out: &mut dyn Output,
+    ) -> Result<(), RenderError> {
+        let params: Vec<Value> = h.params().iter().map(|p| p.value().clone()).collect();
+        let hash: serde_json::Map<String, Value> = h
+            .hash()
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.value().clone()))
+            .collect();
+
+        let mut scope = Scope::new();
+        scope.push("params", value_to_dynamic(&Value::Array(params))?);
+        scope.push("hash", value_to_dynamic(&Value::Object(hash))?);
+        scope.push("ctx", value_to_dynamic(ctx.data())?);
+
+        let result: Dynamic = self
+            .engine
+            .eval_ast_with_scope(&mut scope, &self.ast)
+            .map_err(|e| RenderError::from(RenderErrorReason::Other(format!("script: {}", e))))?;
+
+        let value = dynamic_to_value(result)?;
+        let rendered = match &value {
+            Value::String(s) => s.clone(),
+            Value::Null => String::new(),
+            other => serde_json::to_string(other).unwrap_or_default(),
+        };
+        out.write(&rendered)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod compiled_script_helper_tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn sees_params_and_hash_without_recompiling() {
+        let mut handlebars = Handlebars::new();
+        let helper = CompiledScriptHelper::compile("params[0] + hash.offset").unwrap();
+        handlebars.register_helper("addOffset", Box::new(helper));
+
+        assert_eq!(
+            handlebars
+                .render_template("{{addOffset 10 offset=5}}", &json!({}))
+                .unwrap(),
+            "15"
+        );
+    }
+
+    #[test]
+    fn invalid_script_fails_to_compile() {
+        assert!(CompiledScriptHelper::compile("this is not valid rhai ((").is_err());
+    }
+}
+
+#[cfg(test)]
+mod script_helper_tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn evaluates_arithmetic_from_hash_args() {
+        let mut handlebars = Handlebars::new();
+        handlebars.register_helper("script", Box::new(ScriptHelper {}));
+
+        assert_eq!(
+            handlebars
+                .render_template(
+                    "{{script \"tokenBudget - used\" tokenBudget=1000 used=250}}",
+                    &json!({})
+                )
+                .unwrap(),
+            "750"
+        );
+    }
+
+    #[test]
+    fn block_form_renders_inverse_when_falsy() {
+        let mut handlebars = Handlebars::new();
+        handlebars.register_helper("script", Box::new(ScriptHelper {}));
+
+        assert_eq!(
+            handlebars
+                .render_template(
+                    "{{#script \"used > budget\" used=900 budget=1000}}over{{else}}ok{{/script}}",
+                    &json!({})
+                )
+                .unwrap(),
+            "ok"
+        );
+    }
+
+    #[test]
+    fn block_form_treats_zero_as_falsy() {
+        let mut handlebars = Handlebars::new();
+        handlebars.register_helper("script", Box::new(ScriptHelper {}));
+
+        assert_eq!(
+            handlebars
+                .render_template("{{#script \"0\"}}yes{{else}}no{{/script}}", &json!({}))
+                .unwrap(),
+            "no"
+        );
+    }
+
+    #[test]
+    fn block_form_treats_empty_string_as_falsy() {
+        let mut handlebars = Handlebars::new();
+        handlebars.register_helper("script", Box::new(ScriptHelper {}));
+
+        assert_eq!(
+            handlebars
+                .render_template("{{#script \"\\\"\\\"\"}}yes{{else}}no{{/script}}", &json!({}))
+                .unwrap(),
+            "no"
+        );
+    }
+
+    #[test]
+    fn compile_errors_surface_as_render_errors() {
+        let mut handlebars = Handlebars::new();
+        handlebars.register_helper("script", Box::new(ScriptHelper {}));
+
+        assert!(handlebars
+            .render_template("{{script \"this is not valid rhai ((\"}}", &json!({}))
+            .is_err());
+    }
+
+    #[test]
+    fn eval_disallows_eval_symbol() {
+        let mut handlebars = Handlebars::new();
+        handlebars.register_helper("script", Box::new(ScriptHelper {}));
+
+        assert!(handlebars
+            .render_template("{{script \"eval(\\\"1+1\\\")\"}}", &json!({}))
+            .is_err());
+    }
+}